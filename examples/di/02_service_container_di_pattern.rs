@@ -0,0 +1,341 @@
+// Service Container Dependency Injection Pattern - Rust Example
+//
+// The previous example (01_polyglot_di_pattern.rs) wires every dependency by
+// hand in main(): `UserRepository::new(&database, &logger)`, then
+// `NotificationService::new(&http_client, &logger)`, then `UserService::new(...)`.
+// That's fine for four services. It stops scaling once a graph has dozens of
+// them, because every new service means another manual wiring step at every
+// call site that needs it.
+//
+// This example adds a `Container` that owns construction instead. Callers
+// register providers once, up front, then ask for what they need:
+//
+//     container.register(database);
+//     container.register_factory(|c| {
+//         UserRepository::new(c.resolve().unwrap(), c.resolve().unwrap())
+//     });
+//     let service = container.resolve::<UserService>()?;
+//
+// Internally the container is just a type-keyed map plus singleton caching -
+// the same "registry of constructors" idea as a Python `dict[type, Callable]`
+// container or a Go `map[reflect.Type]func() any]`, expressed with `TypeId`.
+
+use std::any::{Any, TypeId};
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::{Arc, Mutex};
+
+// ==============================================================================
+// Domain Models (Pure Business Logic - No Framework Dependencies)
+// ==============================================================================
+
+#[derive(Debug, Clone)]
+struct User {
+    id: i32,
+    name: String,
+    email: String,
+}
+
+// ==============================================================================
+// Infrastructure Layer (Implements Technical Concerns)
+// ==============================================================================
+
+struct Database {
+    connection_string: String,
+}
+
+impl Database {
+    fn new(connection_string: String) -> Self {
+        println!("[Database] Connected to {}", connection_string);
+        Self { connection_string }
+    }
+
+    fn query(&self, sql: &str) -> Vec<HashMap<String, String>> {
+        println!("[Database] Executing: {}", sql);
+        let mut row = HashMap::new();
+        row.insert("id".to_string(), "1".to_string());
+        row.insert("name".to_string(), "Alice".to_string());
+        row.insert("email".to_string(), "alice@example.com".to_string());
+        vec![row]
+    }
+}
+
+struct HTTPClient {
+    base_url: String,
+    timeout: u32,
+}
+
+impl HTTPClient {
+    fn new(base_url: String, timeout: u32) -> Self {
+        println!("[HTTPClient] Configured for {} (timeout: {}s)", base_url, timeout);
+        Self { base_url, timeout }
+    }
+
+    fn post(&self, path: &str, data: HashMap<String, String>) -> HashMap<String, String> {
+        let url = format!("{}{}", self.base_url, path);
+        println!("[HTTPClient] POST {} with {:?}", url, data);
+        let mut response = HashMap::new();
+        response.insert("status".to_string(), "success".to_string());
+        response.insert("message".to_string(), "User created".to_string());
+        response
+    }
+}
+
+struct Logger {
+    level: String,
+}
+
+impl Logger {
+    fn new(level: String) -> Self {
+        println!("[Logger] Initialized with level {}", level);
+        Self { level }
+    }
+
+    fn info(&self, message: &str) {
+        println!("[INFO] {}", message);
+    }
+
+    fn error(&self, message: &str) {
+        println!("[ERROR] {}", message);
+    }
+}
+
+// ==============================================================================
+// Container (Typed Service Locator)
+// ==============================================================================
+
+/// Errors produced while resolving a service from the `Container`.
+#[derive(Debug)]
+enum ContainerError {
+    /// No provider (instance or factory) was registered for the requested type.
+    NotRegistered(&'static str),
+}
+
+impl fmt::Display for ContainerError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ContainerError::NotRegistered(type_name) => {
+                write!(f, "no provider registered for type `{}`", type_name)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ContainerError {}
+
+/// A provider is either a ready-made instance or a factory that builds one
+/// lazily from the container (so a factory can resolve its own dependencies).
+enum Provider {
+    Instance(Arc<dyn Any + Send + Sync>),
+    Factory(Box<dyn Fn(&Container) -> Arc<dyn Any + Send + Sync> + Send + Sync>),
+}
+
+/// A typed service locator. Register providers with `register` (an existing
+/// instance) or `register_factory` (built lazily from other registrations),
+/// then look them up with `resolve::<T>()`.
+///
+/// Factory results are cached on first resolve, so every service behaves as
+/// a process-wide singleton - resolving `UserService` twice returns the same
+/// `Arc`.
+struct Container {
+    providers: Mutex<HashMap<TypeId, Arc<Provider>>>,
+    singletons: Mutex<HashMap<TypeId, Arc<dyn Any + Send + Sync>>>,
+}
+
+impl Container {
+    fn new() -> Self {
+        Self {
+            providers: Mutex::new(HashMap::new()),
+            singletons: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Register a ready-made instance for `T`.
+    fn register<T: Any + Send + Sync>(&self, value: T) {
+        let type_id = TypeId::of::<T>();
+        self.providers
+            .lock()
+            .unwrap()
+            .insert(type_id, Arc::new(Provider::Instance(Arc::new(value))));
+    }
+
+    /// Register a factory that lazily constructs `T`, given access to the
+    /// container so it can resolve its own dependencies.
+    fn register_factory<T, F>(&self, factory: F)
+    where
+        T: Any + Send + Sync,
+        F: Fn(&Container) -> T + Send + Sync + 'static,
+    {
+        let type_id = TypeId::of::<T>();
+        self.providers.lock().unwrap().insert(
+            type_id,
+            Arc::new(Provider::Factory(Box::new(move |container| Arc::new(factory(container))))),
+        );
+    }
+
+    /// Resolve `T`, invoking and caching its factory on first resolve.
+    /// Returns `ContainerError::NotRegistered` if nothing was registered for
+    /// `T` instead of panicking, so a missing dependency surfaces as a
+    /// normal error at resolve time.
+    fn resolve<T: Any + Send + Sync>(&self) -> Result<Arc<T>, ContainerError> {
+        let type_id = TypeId::of::<T>();
+
+        if let Some(existing) = self.singletons.lock().unwrap().get(&type_id) {
+            return Ok(existing.clone().downcast::<T>().expect("TypeId match guarantees downcast"));
+        }
+
+        // Clone the `Arc<Provider>` and drop the `providers` lock before
+        // invoking a factory - a factory resolving its own dependencies
+        // (the whole point of passing it `&Container`) calls back into
+        // `resolve`, which would deadlock on this same non-reentrant lock
+        // if it were still held.
+        let provider = self.providers.lock().unwrap().get(&type_id).cloned();
+        let built = match provider.as_deref() {
+            Some(Provider::Instance(instance)) => instance.clone(),
+            Some(Provider::Factory(factory)) => factory(self),
+            None => return Err(ContainerError::NotRegistered(std::any::type_name::<T>())),
+        };
+
+        self.singletons.lock().unwrap().insert(type_id, built.clone());
+        Ok(built.downcast::<T>().expect("TypeId match guarantees downcast"))
+    }
+}
+
+// ==============================================================================
+// Application Layer (Business Logic Using Infrastructure)
+// ==============================================================================
+
+struct UserRepository {
+    db: Arc<Database>,
+    logger: Arc<Logger>,
+}
+
+impl UserRepository {
+    fn new(db: Arc<Database>, logger: Arc<Logger>) -> Self {
+        logger.info("UserRepository initialized");
+        Self { db, logger }
+    }
+
+    fn find_by_id(&self, user_id: i32) -> Option<User> {
+        self.logger.info(&format!("Finding user {}", user_id));
+        let rows = self.db.query(&format!("SELECT * FROM users WHERE id = {}", user_id));
+        if rows.is_empty() {
+            return None;
+        }
+        let row = &rows[0];
+        Some(User {
+            id: row.get("id").unwrap().parse().unwrap(),
+            name: row.get("name").unwrap().clone(),
+            email: row.get("email").unwrap().clone(),
+        })
+    }
+}
+
+struct NotificationService {
+    http_client: Arc<HTTPClient>,
+    logger: Arc<Logger>,
+}
+
+impl NotificationService {
+    fn new(http_client: Arc<HTTPClient>, logger: Arc<Logger>) -> Self {
+        logger.info("NotificationService initialized");
+        Self { http_client, logger }
+    }
+
+    fn notify_user_created(&self, user: &User) -> bool {
+        self.logger.info(&format!("Sending notification for user {}", user.name));
+        let mut data = HashMap::new();
+        data.insert("user_id".to_string(), user.id.to_string());
+        data.insert("event".to_string(), "user.created".to_string());
+        let response = self.http_client.post("/notifications", data);
+        response.get("status").map(|s| s == "success").unwrap_or(false)
+    }
+}
+
+struct UserService {
+    repository: Arc<UserRepository>,
+    notifications: Arc<NotificationService>,
+    logger: Arc<Logger>,
+}
+
+impl UserService {
+    fn new(repository: Arc<UserRepository>, notifications: Arc<NotificationService>, logger: Arc<Logger>) -> Self {
+        logger.info("UserService initialized");
+        Self {
+            repository,
+            notifications,
+            logger,
+        }
+    }
+
+    fn get_user(&self, user_id: i32) -> Option<User> {
+        self.logger.info(&format!("Getting user {}", user_id));
+        if let Some(user) = self.repository.find_by_id(user_id) {
+            self.logger.info(&format!("Found user: {}", user.name));
+            self.notifications.notify_user_created(&user);
+            Some(user)
+        } else {
+            None
+        }
+    }
+}
+
+// ==============================================================================
+// Composition Root (Application Entry Point)
+// ==============================================================================
+
+fn main() {
+    println!("======================================================================");
+    println!("Rust Service Container Dependency Injection Example");
+    println!("======================================================================");
+
+    let container = Container::new();
+
+    // Step 1: Register infrastructure as ready-made instances.
+    println!("\n[Composition Root] Registering infrastructure dependencies...");
+    container.register(Database::new("postgresql://localhost/myapp".to_string()));
+    container.register(HTTPClient::new("https://api.example.com".to_string(), 30));
+    container.register(Logger::new("INFO".to_string()));
+
+    // Step 2: Register application services as factories. Each factory pulls
+    // its own dependencies out of the container, so the composition root no
+    // longer has to know the full dependency graph - it just describes how
+    // to build each piece.
+    println!("\n[Composition Root] Registering application services...");
+    container.register_factory(|c| {
+        UserRepository::new(c.resolve::<Database>().unwrap(), c.resolve::<Logger>().unwrap())
+    });
+    container.register_factory(|c| {
+        NotificationService::new(c.resolve::<HTTPClient>().unwrap(), c.resolve::<Logger>().unwrap())
+    });
+    container.register_factory(|c| {
+        UserService::new(
+            c.resolve::<UserRepository>().unwrap(),
+            c.resolve::<NotificationService>().unwrap(),
+            c.resolve::<Logger>().unwrap(),
+        )
+    });
+
+    // Step 3: Resolve the service we actually need. The container builds
+    // (and caches) everything underneath it on demand.
+    println!("\n[Composition Root] Resolving UserService...");
+    let user_service = container.resolve::<UserService>().expect("UserService is registered");
+
+    // Step 4: Run the application.
+    println!("\n======================================================================");
+    println!("Running Application");
+    println!("======================================================================\n");
+
+    if let Some(user) = user_service.get_user(1) {
+        println!("\n✅ Successfully retrieved user: {} ({})", user.name, user.email);
+    } else {
+        println!("\n❌ User not found");
+    }
+
+    // A request for something nobody registered fails at resolve time with a
+    // typed error, instead of panicking deep inside some unrelated method.
+    match container.resolve::<HashMap<String, String>>() {
+        Ok(_) => unreachable!("nothing registers a bare HashMap"),
+        Err(err) => println!("\n[Composition Root] Expected resolve failure: {}", err),
+    }
+}
@@ -0,0 +1,301 @@
+// Trait Abstraction Dependency Injection Pattern - Rust Example
+//
+// In 02_service_container_di_pattern.rs, `UserRepository` and
+// `NotificationService` still hold concrete `Arc<Database>` / `Arc<HTTPClient>`
+// references. The container decides *when* those get built, but `UserService`
+// can still only ever be tested against real infrastructure - there's no seam
+// to slide a fake in.
+//
+// This example introduces that seam: object-safe traits for the things
+// `UserService` actually needs (`UserStore`, `Notifier`, `Log`), and makes
+// `UserService` depend on `&dyn Trait` instead of concrete structs. Production
+// code registers the real `Database`-backed, `HTTPClient`-backed types; tests
+// register in-memory fakes. `UserService` itself never changes.
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+// ==============================================================================
+// Domain Models (Pure Business Logic - No Framework Dependencies)
+// ==============================================================================
+
+#[derive(Debug, Clone, PartialEq)]
+struct User {
+    id: i32,
+    name: String,
+    email: String,
+}
+
+// ==============================================================================
+// Trait Abstractions (The Seam Between Domain Logic and Infrastructure)
+// ==============================================================================
+
+/// Anything that can look a user up by id - a real database-backed
+/// repository, or an in-memory fake.
+trait UserStore {
+    fn find_by_id(&self, id: i32) -> Option<User>;
+}
+
+/// Anything that can notify the outside world a user was created.
+trait Notifier {
+    fn notify_user_created(&self, user: &User) -> bool;
+}
+
+/// Anything that can log info/error messages.
+trait Log {
+    fn info(&self, msg: &str);
+    fn error(&self, msg: &str);
+}
+
+// ==============================================================================
+// Infrastructure Layer (Real Implementations of the Traits)
+// ==============================================================================
+
+struct Database {
+    connection_string: String,
+}
+
+impl Database {
+    fn new(connection_string: String) -> Self {
+        println!("[Database] Connected to {}", connection_string);
+        Self { connection_string }
+    }
+
+    fn query(&self, sql: &str) -> Vec<HashMap<String, String>> {
+        println!("[Database] Executing: {}", sql);
+        let mut row = HashMap::new();
+        row.insert("id".to_string(), "1".to_string());
+        row.insert("name".to_string(), "Alice".to_string());
+        row.insert("email".to_string(), "alice@example.com".to_string());
+        vec![row]
+    }
+}
+
+struct HTTPClient {
+    base_url: String,
+    timeout: u32,
+}
+
+impl HTTPClient {
+    fn new(base_url: String, timeout: u32) -> Self {
+        println!("[HTTPClient] Configured for {} (timeout: {}s)", base_url, timeout);
+        Self { base_url, timeout }
+    }
+
+    fn post(&self, path: &str, data: HashMap<String, String>) -> HashMap<String, String> {
+        let url = format!("{}{}", self.base_url, path);
+        println!("[HTTPClient] POST {} with {:?}", url, data);
+        let mut response = HashMap::new();
+        response.insert("status".to_string(), "success".to_string());
+        response.insert("message".to_string(), "User created".to_string());
+        response
+    }
+}
+
+struct Logger {
+    level: String,
+}
+
+impl Logger {
+    fn new(level: String) -> Self {
+        println!("[Logger] Initialized with level {}", level);
+        Self { level }
+    }
+}
+
+impl Log for Logger {
+    fn info(&self, message: &str) {
+        println!("[INFO] {}", message);
+    }
+
+    fn error(&self, message: &str) {
+        println!("[ERROR] {}", message);
+    }
+}
+
+struct UserRepository<'a> {
+    db: &'a Database,
+    logger: &'a dyn Log,
+}
+
+impl<'a> UserRepository<'a> {
+    fn new(db: &'a Database, logger: &'a dyn Log) -> Self {
+        logger.info("UserRepository initialized");
+        Self { db, logger }
+    }
+}
+
+impl<'a> UserStore for UserRepository<'a> {
+    fn find_by_id(&self, user_id: i32) -> Option<User> {
+        self.logger.info(&format!("Finding user {}", user_id));
+        let rows = self.db.query(&format!("SELECT * FROM users WHERE id = {}", user_id));
+        if rows.is_empty() {
+            return None;
+        }
+        let row = &rows[0];
+        Some(User {
+            id: row.get("id").unwrap().parse().unwrap(),
+            name: row.get("name").unwrap().clone(),
+            email: row.get("email").unwrap().clone(),
+        })
+    }
+}
+
+struct NotificationService<'a> {
+    http_client: &'a HTTPClient,
+    logger: &'a dyn Log,
+}
+
+impl<'a> NotificationService<'a> {
+    fn new(http_client: &'a HTTPClient, logger: &'a dyn Log) -> Self {
+        logger.info("NotificationService initialized");
+        Self { http_client, logger }
+    }
+}
+
+impl<'a> Notifier for NotificationService<'a> {
+    fn notify_user_created(&self, user: &User) -> bool {
+        self.logger.info(&format!("Sending notification for user {}", user.name));
+        let mut data = HashMap::new();
+        data.insert("user_id".to_string(), user.id.to_string());
+        data.insert("event".to_string(), "user.created".to_string());
+        let response = self.http_client.post("/notifications", data);
+        response.get("status").map(|s| s == "success").unwrap_or(false)
+    }
+}
+
+// ==============================================================================
+// Fakes (In-Memory Implementations of the Traits, for Tests)
+// ==============================================================================
+
+/// A `UserStore` backed by an in-memory `Vec`, so tests don't need a
+/// `Database`.
+struct InMemoryUserStore {
+    users: Vec<User>,
+}
+
+impl InMemoryUserStore {
+    fn new(users: Vec<User>) -> Self {
+        Self { users }
+    }
+}
+
+impl UserStore for InMemoryUserStore {
+    fn find_by_id(&self, id: i32) -> Option<User> {
+        self.users.iter().find(|u| u.id == id).cloned()
+    }
+}
+
+/// A `Notifier` that records every call instead of making an HTTP request, so
+/// a test can assert on what would have been sent.
+struct RecordingNotifier {
+    calls: Mutex<Vec<User>>,
+}
+
+impl RecordingNotifier {
+    fn new() -> Self {
+        Self { calls: Mutex::new(Vec::new()) }
+    }
+
+    fn recorded_calls(&self) -> Vec<User> {
+        self.calls.lock().unwrap().clone()
+    }
+}
+
+impl Notifier for RecordingNotifier {
+    fn notify_user_created(&self, user: &User) -> bool {
+        self.calls.lock().unwrap().push(user.clone());
+        true
+    }
+}
+
+/// A `Log` that discards everything, for tests that don't care about log
+/// output.
+struct NullLogger;
+
+impl Log for NullLogger {
+    fn info(&self, _msg: &str) {}
+    fn error(&self, _msg: &str) {}
+}
+
+// ==============================================================================
+// Application Layer (Business Logic Depending Only on Traits)
+// ==============================================================================
+
+struct UserService<'a> {
+    repository: &'a dyn UserStore,
+    notifications: &'a dyn Notifier,
+    logger: &'a dyn Log,
+}
+
+impl<'a> UserService<'a> {
+    fn new(repository: &'a dyn UserStore, notifications: &'a dyn Notifier, logger: &'a dyn Log) -> Self {
+        logger.info("UserService initialized");
+        Self {
+            repository,
+            notifications,
+            logger,
+        }
+    }
+
+    fn get_user(&self, user_id: i32) -> Option<User> {
+        self.logger.info(&format!("Getting user {}", user_id));
+        if let Some(user) = self.repository.find_by_id(user_id) {
+            self.logger.info(&format!("Found user: {}", user.name));
+            self.notifications.notify_user_created(&user);
+            Some(user)
+        } else {
+            None
+        }
+    }
+}
+
+// ==============================================================================
+// Composition Root (Application Entry Point)
+// ==============================================================================
+
+fn main() {
+    println!("======================================================================");
+    println!("Rust Trait Abstraction Dependency Injection Example");
+    println!("======================================================================");
+
+    // --- Production wiring: real infrastructure behind the traits. ---
+    println!("\n[Composition Root] Wiring production UserService against real infrastructure...");
+    let database = Database::new("postgresql://localhost/myapp".to_string());
+    let http_client = HTTPClient::new("https://api.example.com".to_string(), 30);
+    let logger = Logger::new("INFO".to_string());
+    let repository = UserRepository::new(&database, &logger);
+    let notifications = NotificationService::new(&http_client, &logger);
+    let user_service = UserService::new(&repository, &notifications, &logger);
+
+    if let Some(user) = user_service.get_user(1) {
+        println!("\n✅ Production UserService retrieved: {} ({})", user.name, user.email);
+    }
+
+    // --- Test-style wiring: fakes behind the same traits, no Database or
+    // HTTPClient in sight. This is what makes the abstraction pay off. ---
+    println!("\n[Composition Root] Exercising UserService against fakes...");
+    let fake_store = InMemoryUserStore::new(vec![User {
+        id: 42,
+        name: "Fake Alice".to_string(),
+        email: "fake-alice@example.com".to_string(),
+    }]);
+    let recording_notifier = RecordingNotifier::new();
+    let null_logger = NullLogger;
+    let fake_service = UserService::new(&fake_store, &recording_notifier, &null_logger);
+
+    let found = fake_service.get_user(42);
+    assert_eq!(
+        found,
+        Some(User {
+            id: 42,
+            name: "Fake Alice".to_string(),
+            email: "fake-alice@example.com".to_string(),
+        })
+    );
+    assert_eq!(recording_notifier.recorded_calls().len(), 1);
+    assert_eq!(recording_notifier.recorded_calls()[0].id, 42);
+
+    println!("✅ UserService works end-to-end against fakes - no Database, no HTTPClient needed");
+    println!("✅ RecordingNotifier captured {} notification(s)", recording_notifier.recorded_calls().len());
+}
@@ -0,0 +1,323 @@
+// Async Connection Pool Dependency Injection Pattern - Rust Example
+//
+// Every previous example opens a single `Database` connection and treats
+// `query` as a cheap, blocking call. That's fine for a demo, but it can't
+// serve concurrent requests: every caller would be fighting over the same
+// connection, and there's no way to bound how many connections get opened.
+//
+// This example adds a `Pool<Database>` modeled on deadpool: it owns up to
+// `max_size` connections, hands them out as `PooledConnection` guards via an
+// async `acquire().await` that waits when the pool is exhausted, and returns
+// them automatically on drop. A health check (`Database::ping`) re-validates
+// an idle connection before it's handed back out, and `acquire` times out with a typed
+// `PoolError::Timeout` instead of hanging forever.
+//
+// This example needs a `tokio` dependency (`rt-multi-thread`, `macros`,
+// `sync`, and `time` features) to build - this repo doesn't carry a
+// Cargo.toml yet, so `cargo run --example` isn't wired up here; treat this
+// file as a reference for the shape the pool would take once it is.
+
+use std::collections::VecDeque;
+use std::fmt;
+use std::sync::Arc;
+use std::time::Duration;
+
+use tokio::sync::{Mutex, Notify};
+use tokio::time::timeout;
+
+// ==============================================================================
+// Domain Models (Pure Business Logic - No Framework Dependencies)
+// ==============================================================================
+
+#[derive(Debug, Clone)]
+struct User {
+    id: i32,
+    name: String,
+}
+
+// ==============================================================================
+// Infrastructure Layer (Implements Technical Concerns)
+// ==============================================================================
+
+/// A single database connection. Connecting and querying are modeled as
+/// async to match a real driver (e.g. `sqlx`), even though this example
+/// mocks the actual I/O.
+struct Database {
+    id: u32,
+    connection_string: String,
+    healthy: bool,
+}
+
+impl Database {
+    async fn connect(id: u32, connection_string: &str) -> Self {
+        println!("[Database #{id}] Connected to {connection_string}");
+        Self {
+            id,
+            connection_string: connection_string.to_string(),
+            healthy: true,
+        }
+    }
+
+    async fn query(&self, sql: &str) -> Vec<User> {
+        println!("[Database #{}] Executing: {}", self.id, sql);
+        vec![User { id: 1, name: "Alice".to_string() }]
+    }
+
+    /// A trivial health-check query, re-run before a connection is handed
+    /// back out of the pool.
+    async fn ping(&self) -> bool {
+        self.healthy
+    }
+}
+
+// ==============================================================================
+// Connection Pool (Bounded Concurrency + Health Checks)
+// ==============================================================================
+
+/// Errors produced while acquiring a connection from the `Pool`.
+#[derive(Debug)]
+enum PoolError {
+    /// No connection became available before `acquire`'s timeout elapsed.
+    Timeout,
+}
+
+impl fmt::Display for PoolError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PoolError::Timeout => write!(f, "timed out waiting for a pooled connection"),
+        }
+    }
+}
+
+impl std::error::Error for PoolError {}
+
+struct PoolInner {
+    idle: VecDeque<Database>,
+    /// Connections currently checked out, so the pool knows how many more it
+    /// is allowed to open (`idle.len() + in_use < max_size`).
+    in_use: usize,
+    max_size: usize,
+    connection_string: String,
+    next_id: u32,
+}
+
+/// A bounded pool of `Database` connections, modeled on deadpool.
+///
+/// `acquire().await` hands out a `PooledConnection` guard, blocking while the
+/// pool is fully checked out and failing with `PoolError::Timeout` if nothing
+/// frees up in time. Dropping the guard returns the connection to the pool
+/// automatically.
+struct Pool {
+    inner: Mutex<PoolInner>,
+    /// Signaled whenever a connection is returned, so a waiting `acquire`
+    /// can re-check for availability instead of polling.
+    released: Notify,
+    acquire_timeout: Duration,
+}
+
+impl Pool {
+    async fn new(connection_string: String, max_size: usize, acquire_timeout: Duration) -> Arc<Self> {
+        Arc::new(Self {
+            inner: Mutex::new(PoolInner {
+                idle: VecDeque::new(),
+                in_use: 0,
+                max_size,
+                connection_string,
+                next_id: 0,
+            }),
+            released: Notify::new(),
+            acquire_timeout,
+        })
+    }
+
+    async fn acquire(self: &Arc<Self>) -> Result<PooledConnection, PoolError> {
+        timeout(self.acquire_timeout, self.acquire_inner())
+            .await
+            .map_err(|_| PoolError::Timeout)
+    }
+
+    async fn acquire_inner(self: &Arc<Self>) -> PooledConnection {
+        loop {
+            {
+                let mut inner = self.inner.lock().await;
+
+                if let Some(mut conn) = inner.idle.pop_front() {
+                    // Health-check before handing a recycled connection back
+                    // out; discard and replace it on failure.
+                    if conn.ping().await {
+                        inner.in_use += 1;
+                        return PooledConnection {
+                            pool: self.clone(),
+                            conn: Some(conn),
+                        };
+                    }
+                    println!("[Pool] Discarding unhealthy connection #{}", conn.id);
+                    conn = Database::connect(inner.next_id, &inner.connection_string).await;
+                    inner.next_id += 1;
+                    inner.in_use += 1;
+                    return PooledConnection {
+                        pool: self.clone(),
+                        conn: Some(conn),
+                    };
+                }
+
+                if inner.in_use < inner.max_size {
+                    let id = inner.next_id;
+                    inner.next_id += 1;
+                    let conn = Database::connect(id, &inner.connection_string).await;
+                    inner.in_use += 1;
+                    return PooledConnection {
+                        pool: self.clone(),
+                        conn: Some(conn),
+                    };
+                }
+            }
+
+            // Pool is fully checked out - wait for a release and try again.
+            self.released.notified().await;
+        }
+    }
+
+    /// Returns a connection to the idle queue and wakes one waiter. Always
+    /// awaits the lock rather than a `try_lock` - a `try_lock` that bails
+    /// out under contention would drop the connection and permanently
+    /// shrink the pool's effective capacity. Called from a task spawned by
+    /// `PooledConnection::drop`, since `drop` itself can't `.await`.
+    async fn release(&self, conn: Database) {
+        let mut inner = self.inner.lock().await;
+        inner.in_use -= 1;
+        inner.idle.push_back(conn);
+        self.released.notify_one();
+    }
+
+    /// Number of connections currently idle, for demonstrating that the
+    /// pool recovers its full capacity after a burst of contention.
+    async fn idle_count(&self) -> usize {
+        self.inner.lock().await.idle.len()
+    }
+}
+
+/// RAII guard handed out by `Pool::acquire`. Derefs to `Database`; returns
+/// the connection to the pool automatically when dropped.
+struct PooledConnection {
+    pool: Arc<Pool>,
+    conn: Option<Database>,
+}
+
+impl std::ops::Deref for PooledConnection {
+    type Target = Database;
+
+    fn deref(&self) -> &Database {
+        self.conn.as_ref().expect("connection taken only on drop")
+    }
+}
+
+impl Drop for PooledConnection {
+    fn drop(&mut self) {
+        if let Some(conn) = self.conn.take() {
+            // `drop` can't `.await` - hand the connection off to a spawned
+            // task so `release` can always await the lock instead of
+            // risking a lossy `try_lock`.
+            let pool = self.pool.clone();
+            tokio::spawn(async move {
+                pool.release(conn).await;
+            });
+        }
+    }
+}
+
+// ==============================================================================
+// Application Layer (Business Logic Using the Pool)
+// ==============================================================================
+
+struct UserRepository {
+    pool: Arc<Pool>,
+}
+
+impl UserRepository {
+    fn new(pool: Arc<Pool>) -> Self {
+        Self { pool }
+    }
+
+    async fn find_by_id(&self, user_id: i32) -> Option<User> {
+        let conn = self.pool.acquire().await.ok()?;
+        let rows = conn.query(&format!("SELECT * FROM users WHERE id = {}", user_id)).await;
+        rows.into_iter().next()
+    }
+}
+
+// ==============================================================================
+// Composition Root (Application Entry Point)
+// ==============================================================================
+
+#[tokio::main]
+async fn main() {
+    println!("======================================================================");
+    println!("Rust Async Connection Pool Dependency Injection Example");
+    println!("======================================================================");
+
+    println!("\n[Composition Root] Creating a bounded connection pool (max_size = 2)...");
+    let pool = Pool::new(
+        "postgresql://localhost/myapp".to_string(),
+        2,
+        Duration::from_secs(1),
+    )
+    .await;
+    let repository = Arc::new(UserRepository::new(pool.clone()));
+
+    println!("\n======================================================================");
+    println!("Running Application");
+    println!("======================================================================\n");
+
+    // Two requests in flight at once, within the pool's max_size - both
+    // should get served without waiting on each other.
+    let first = {
+        let repository = repository.clone();
+        tokio::spawn(async move { repository.find_by_id(1).await })
+    };
+    let second = {
+        let repository = repository.clone();
+        tokio::spawn(async move { repository.find_by_id(1).await })
+    };
+
+    let (first, second) = tokio::join!(first, second);
+    if let (Some(user), Some(_)) = (first.unwrap(), second.unwrap()) {
+        println!("\n✅ Concurrent requests both retrieved user: {}", user.name);
+    }
+
+    // A third request beyond max_size, with both connections still checked
+    // out, demonstrates the timeout path.
+    println!("\n[Composition Root] Holding both pooled connections and requesting a third...");
+    let held_one = pool.acquire().await.expect("first connection available");
+    let held_two = pool.acquire().await.expect("second connection available");
+    match pool.acquire().await {
+        Ok(_) => unreachable!("pool is fully checked out"),
+        Err(err) => println!("❌ Expected acquire failure: {}", err),
+    }
+    drop(held_one);
+    drop(held_two);
+
+    // A burst of contending acquire/release cycles, well beyond max_size,
+    // demonstrates that releasing never loses a connection: every task
+    // acquires, queries, and drops its guard, and the pool should end up
+    // back at max_size idle connections with none leaked.
+    println!("\n[Composition Root] Running 200 contending requests against max_size = 2...");
+    let mut tasks = Vec::new();
+    for _ in 0..200 {
+        let repository = repository.clone();
+        tasks.push(tokio::spawn(async move {
+            repository.find_by_id(1).await;
+        }));
+    }
+    for task in tasks {
+        task.await.expect("request task panicked");
+    }
+
+    // Released connections are returned by a spawned task (drop can't
+    // await), so give the last few releases a moment to land before
+    // checking the pool settled back to full idle capacity.
+    tokio::time::sleep(Duration::from_millis(50)).await;
+    let idle = pool.idle_count().await;
+    assert_eq!(idle, 2, "pool should recover to max_size idle connections, found {idle}");
+    println!("✅ Pool recovered to {idle}/2 idle connections after the contention burst - no leaked connections");
+}
@@ -0,0 +1,264 @@
+// Middleware Pipeline Dependency Injection Pattern - Rust Example
+//
+// `UserService::get_user` (see 01_polyglot_di_pattern.rs) inlines its
+// cross-cutting concerns directly: `logger.info(...)` at entry, again at
+// exit, more if you wanted timing or retries. Every method that wants the
+// same behavior has to copy-paste the same calls, and there's no way for a
+// caller to add a new concern (say, retries) without editing the service.
+//
+// This example extracts those concerns into a `Middleware` trait and a
+// `Stack` that composes a `Vec<Box<dyn Middleware>>` into a single call
+// chain - the same "wrap the next layer's closure" shape as an Actix/Tower
+// `Transform`. `UserService::get_user` becomes the innermost layer; timing,
+// retries, and logging wrap around it without touching its body.
+
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::time::Instant;
+
+// ==============================================================================
+// Domain Models (Pure Business Logic - No Framework Dependencies)
+// ==============================================================================
+
+#[derive(Debug, Clone)]
+struct User {
+    id: i32,
+    name: String,
+    email: String,
+}
+
+// ==============================================================================
+// Infrastructure Layer (Implements Technical Concerns)
+// ==============================================================================
+
+struct Database {
+    connection_string: String,
+}
+
+impl Database {
+    fn new(connection_string: String) -> Self {
+        println!("[Database] Connected to {}", connection_string);
+        Self { connection_string }
+    }
+
+    fn query(&self, sql: &str) -> Vec<HashMap<String, String>> {
+        println!("[Database] Executing: {}", sql);
+        let mut row = HashMap::new();
+        row.insert("id".to_string(), "1".to_string());
+        row.insert("name".to_string(), "Alice".to_string());
+        row.insert("email".to_string(), "alice@example.com".to_string());
+        vec![row]
+    }
+}
+
+struct Logger {
+    level: String,
+}
+
+impl Logger {
+    fn new(level: String) -> Self {
+        println!("[Logger] Initialized with level {}", level);
+        Self { level }
+    }
+
+    fn info(&self, message: &str) {
+        println!("[INFO] {}", message);
+    }
+}
+
+// ==============================================================================
+// Middleware Pipeline
+// ==============================================================================
+
+/// The request/response types flowing through the pipeline. Kept generic
+/// enough to describe any `UserService` call.
+#[derive(Debug, Clone)]
+struct GetUserRequest {
+    user_id: i32,
+}
+
+#[derive(Debug, Clone)]
+struct GetUserResponse {
+    user: Option<User>,
+    /// Set by the innermost handler when the call should be retried (e.g. the
+    /// repository returned nothing because of a transient failure, modeled
+    /// here as a missing user on the first try); read by `RetryMiddleware`.
+    should_retry: bool,
+}
+
+/// A single layer in the pipeline. `call` receives the request and a `next`
+/// closure representing every layer further in (ending with the service
+/// itself); it decides whether, when, and how many times to invoke `next`.
+trait Middleware {
+    fn call(&self, req: GetUserRequest, next: &dyn Fn(GetUserRequest) -> GetUserResponse) -> GetUserResponse;
+}
+
+/// Logs that a call entered and left the pipeline.
+struct LoggingMiddleware<'a> {
+    logger: &'a Logger,
+}
+
+impl<'a> Middleware for LoggingMiddleware<'a> {
+    fn call(&self, req: GetUserRequest, next: &dyn Fn(GetUserRequest) -> GetUserResponse) -> GetUserResponse {
+        self.logger.info(&format!("-> get_user({})", req.user_id));
+        let response = next(req.clone());
+        self.logger.info(&format!("<- get_user({}) => {:?}", req.user_id, response.user.as_ref().map(|u| &u.name)));
+        response
+    }
+}
+
+/// Logs how long the inner chain took to run.
+struct TimingMiddleware<'a> {
+    logger: &'a Logger,
+}
+
+impl<'a> Middleware for TimingMiddleware<'a> {
+    fn call(&self, req: GetUserRequest, next: &dyn Fn(GetUserRequest) -> GetUserResponse) -> GetUserResponse {
+        let started = Instant::now();
+        let response = next(req);
+        self.logger.info(&format!("get_user took {:?}", started.elapsed()));
+        response
+    }
+}
+
+/// Re-invokes `next` while the response reports `should_retry`, calling it at
+/// most `max_attempts` times in total (the first attempt included).
+struct RetryMiddleware<'a> {
+    max_attempts: u32,
+    logger: &'a Logger,
+}
+
+impl<'a> Middleware for RetryMiddleware<'a> {
+    fn call(&self, req: GetUserRequest, next: &dyn Fn(GetUserRequest) -> GetUserResponse) -> GetUserResponse {
+        let mut response = next(req.clone());
+        let mut attempt = 1;
+        while response.should_retry && attempt < self.max_attempts {
+            attempt += 1;
+            self.logger.info(&format!("Retrying get_user({}) (attempt {})", req.user_id, attempt));
+            response = next(req.clone());
+        }
+        response
+    }
+}
+
+/// Composes a `Vec<Box<dyn Middleware>>` and an innermost handler into a
+/// single callable chain - the first middleware in the `Vec` is the
+/// outermost wrapper, matching the order callers register them in.
+///
+/// Carries an explicit lifetime because the built-in layers below borrow
+/// their `&'a Logger` rather than own it - `dyn Middleware` alone would
+/// default its trait object to `+ 'static` and reject them.
+struct Stack<'a> {
+    layers: Vec<Box<dyn Middleware + 'a>>,
+}
+
+impl<'a> Stack<'a> {
+    fn new(layers: Vec<Box<dyn Middleware + 'a>>) -> Self {
+        Self { layers }
+    }
+
+    fn run(&self, req: GetUserRequest, handler: &dyn Fn(GetUserRequest) -> GetUserResponse) -> GetUserResponse {
+        self.run_from(0, req, handler)
+    }
+
+    /// Recursively calls layer `index`, passing it a `next` that recurses
+    /// into layer `index + 1` - once `index` runs off the end of `layers`,
+    /// `next` is just the innermost `handler`.
+    fn run_from(&self, index: usize, req: GetUserRequest, handler: &dyn Fn(GetUserRequest) -> GetUserResponse) -> GetUserResponse {
+        match self.layers.get(index) {
+            Some(layer) => layer.call(req, &|req| self.run_from(index + 1, req, handler)),
+            None => handler(req),
+        }
+    }
+}
+
+// ==============================================================================
+// Application Layer (Business Logic Using the Pipeline)
+// ==============================================================================
+
+struct UserRepository<'a> {
+    db: &'a Database,
+    /// Counts calls to `find_by_id` so this example can simulate a
+    /// transient failure on the first attempt - exercising
+    /// `RetryMiddleware` the same way a flaky connection would in production.
+    attempts: AtomicU32,
+}
+
+impl<'a> UserRepository<'a> {
+    fn new(db: &'a Database) -> Self {
+        Self { db, attempts: AtomicU32::new(0) }
+    }
+
+    fn find_by_id(&self, user_id: i32) -> Option<User> {
+        if self.attempts.fetch_add(1, Ordering::SeqCst) == 0 {
+            println!("[UserRepository] Simulating a transient failure on the first attempt");
+            return None;
+        }
+
+        let rows = self.db.query(&format!("SELECT * FROM users WHERE id = {}", user_id));
+        if rows.is_empty() {
+            return None;
+        }
+        let row = &rows[0];
+        Some(User {
+            id: row.get("id").unwrap().parse().unwrap(),
+            name: row.get("name").unwrap().clone(),
+            email: row.get("email").unwrap().clone(),
+        })
+    }
+}
+
+/// `get_user` no longer inlines logging or timing - it's pure business logic.
+/// Cross-cutting concerns live entirely in the `Stack` built in `main`.
+struct UserService<'a> {
+    repository: &'a UserRepository<'a>,
+    stack: Stack<'a>,
+}
+
+impl<'a> UserService<'a> {
+    fn new(repository: &'a UserRepository<'a>, stack: Stack<'a>) -> Self {
+        Self { repository, stack }
+    }
+
+    fn get_user(&self, user_id: i32) -> Option<User> {
+        let response = self.stack.run(GetUserRequest { user_id }, &|req| {
+            let user = self.repository.find_by_id(req.user_id);
+            let should_retry = user.is_none();
+            GetUserResponse { user, should_retry }
+        });
+        response.user
+    }
+}
+
+// ==============================================================================
+// Composition Root (Application Entry Point)
+// ==============================================================================
+
+fn main() {
+    println!("======================================================================");
+    println!("Rust Middleware Pipeline Dependency Injection Example");
+    println!("======================================================================");
+
+    println!("\n[Composition Root] Creating infrastructure dependencies...");
+    let database = Database::new("postgresql://localhost/myapp".to_string());
+    let logger = Logger::new("INFO".to_string());
+    let repository = UserRepository::new(&database);
+
+    println!("\n[Composition Root] Building middleware stack (logging -> timing -> retry)...");
+    let stack = Stack::new(vec![
+        Box::new(LoggingMiddleware { logger: &logger }),
+        Box::new(TimingMiddleware { logger: &logger }),
+        Box::new(RetryMiddleware { max_attempts: 3, logger: &logger }),
+    ]);
+    let user_service = UserService::new(&repository, stack);
+
+    println!("\n======================================================================");
+    println!("Running Application");
+    println!("======================================================================\n");
+
+    if let Some(user) = user_service.get_user(1) {
+        println!("\n✅ Successfully retrieved user: {} ({})", user.name, user.email);
+    } else {
+        println!("\n❌ User not found");
+    }
+}
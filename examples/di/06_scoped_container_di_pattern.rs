@@ -0,0 +1,318 @@
+// Scoped Container Lifetimes Dependency Injection Pattern - Rust Example
+//
+// The `Container` in 02_service_container_di_pattern.rs treats every
+// registration as a process-wide singleton: resolve it once, and every
+// caller forever after gets the same cached instance. That's right for a
+// `Database` connection pool, but wrong for something like a per-request
+// `Logger` carrying a correlation id - every request needs its own instance,
+// and it should go away when the request ends.
+//
+// This example adds a `Lifetime` to each registration:
+//   - `Singleton` - cached in the root container forever (the old behavior).
+//   - `Scoped`    - cached within a child `Scope`, dropped when the scope ends.
+//   - `Transient` - the factory re-runs on every resolve, never cached.
+//
+// `container.enter_scope()` opens a `Scope` with its own cache that falls
+// back to the parent container for singletons, so a per-request `UserService`
+// graph can share one global `Database` pool.
+
+use std::any::{Any, TypeId};
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::{Arc, Mutex};
+
+// ==============================================================================
+// Infrastructure Layer (Implements Technical Concerns)
+// ==============================================================================
+
+struct Database {
+    connection_string: String,
+}
+
+impl Database {
+    fn new(connection_string: String) -> Self {
+        println!("[Database] Connected to {}", connection_string);
+        Self { connection_string }
+    }
+}
+
+/// A freshly minted id, handed out by a Transient registration. Nothing
+/// about it is per-scope or per-process - every resolve should see a new
+/// one, which is exactly what distinguishes `Transient` from `Scoped`.
+struct RequestId(u32);
+
+/// A per-request logger carrying a correlation id, so every line it prints
+/// can be traced back to the request that produced it.
+struct Logger {
+    correlation_id: String,
+}
+
+impl Logger {
+    fn new(correlation_id: String) -> Self {
+        Self { correlation_id }
+    }
+
+    fn info(&self, message: &str) {
+        println!("[INFO][{}] {}", self.correlation_id, message);
+    }
+}
+
+// ==============================================================================
+// Container (Typed Service Locator with Lifetime Scopes)
+// ==============================================================================
+
+#[derive(Debug)]
+enum ContainerError {
+    NotRegistered(&'static str),
+    /// A `Scoped` registration was resolved directly on the root container,
+    /// with no active `Scope` to cache it in.
+    NoActiveScope(&'static str),
+}
+
+impl fmt::Display for ContainerError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ContainerError::NotRegistered(type_name) => {
+                write!(f, "no provider registered for type `{}`", type_name)
+            }
+            ContainerError::NoActiveScope(type_name) => {
+                write!(f, "type `{}` is scoped but was resolved with no active scope", type_name)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ContainerError {}
+
+/// How long a resolved instance lives before it's rebuilt.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Lifetime {
+    /// Built once per process and cached in the root container.
+    Singleton,
+    /// Built once per `Scope` and cached there; a new scope gets a new instance.
+    Scoped,
+    /// Rebuilt on every resolve; never cached.
+    Transient,
+}
+
+type AnyArc = Arc<dyn Any + Send + Sync>;
+type Factory = Box<dyn Fn(&Resolver) -> AnyArc + Send + Sync>;
+
+struct Registration {
+    lifetime: Lifetime,
+    factory: Factory,
+}
+
+struct Container {
+    registrations: Mutex<HashMap<TypeId, Arc<Registration>>>,
+    singletons: Mutex<HashMap<TypeId, AnyArc>>,
+}
+
+impl Container {
+    fn new() -> Self {
+        Self {
+            registrations: Mutex::new(HashMap::new()),
+            singletons: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Register a factory for `T` under the given `Lifetime`. The factory
+    /// receives a `Resolver` rather than the raw `Container`, so a nested
+    /// `resolve::<Logger>()` call made from inside, say, `UserService`'s
+    /// factory sees whichever `Scope` is actually resolving `UserService`.
+    fn register<T, F>(&self, lifetime: Lifetime, factory: F)
+    where
+        T: Any + Send + Sync,
+        F: Fn(&Resolver) -> T + Send + Sync + 'static,
+    {
+        let type_id = TypeId::of::<T>();
+        self.registrations.lock().unwrap().insert(
+            type_id,
+            Arc::new(Registration {
+                lifetime,
+                factory: Box::new(move |resolver| Arc::new(factory(resolver))),
+            }),
+        );
+    }
+
+    /// Resolve `T` against the root container. `Scoped` registrations fail
+    /// here - there's no scope for them to live in - use `Scope::resolve`
+    /// instead.
+    fn resolve<T: Any + Send + Sync>(self: &Arc<Self>) -> Result<Arc<T>, ContainerError> {
+        Resolver { container: self.clone(), scope: None }.resolve()
+    }
+
+    fn enter_scope(self: &Arc<Self>) -> Arc<Scope> {
+        Arc::new(Scope {
+            parent: self.clone(),
+            cache: Mutex::new(HashMap::new()),
+        })
+    }
+}
+
+/// A child cache opened by `Container::enter_scope`. `Scoped` registrations
+/// are built once per `Scope` and cached here; `Singleton` registrations
+/// fall back to the parent container so the whole scope graph still shares
+/// one `Database`.
+struct Scope {
+    parent: Arc<Container>,
+    cache: Mutex<HashMap<TypeId, AnyArc>>,
+}
+
+impl Scope {
+    fn resolve<T: Any + Send + Sync>(self: &Arc<Self>) -> Result<Arc<T>, ContainerError> {
+        Resolver { container: self.parent.clone(), scope: Some(self.clone()) }.resolve()
+    }
+}
+
+/// Carries the container plus whichever `Scope` (if any) is currently
+/// resolving, so that resolving a dependency from inside a factory - the
+/// whole point of passing factories a handle back to the locator - stays in
+/// the same scope the outer resolve started in, instead of silently
+/// resolving against the root container.
+struct Resolver {
+    container: Arc<Container>,
+    scope: Option<Arc<Scope>>,
+}
+
+impl Resolver {
+    fn resolve<T: Any + Send + Sync>(&self) -> Result<Arc<T>, ContainerError> {
+        let type_id = TypeId::of::<T>();
+        let type_name = std::any::type_name::<T>();
+
+        if let Some(existing) = self.container.singletons.lock().unwrap().get(&type_id) {
+            return Ok(existing.clone().downcast::<T>().expect("TypeId match guarantees downcast"));
+        }
+        if let Some(scope) = &self.scope {
+            if let Some(existing) = scope.cache.lock().unwrap().get(&type_id) {
+                return Ok(existing.clone().downcast::<T>().expect("TypeId match guarantees downcast"));
+            }
+        }
+
+        // Clone the `Arc<Registration>` and drop the `registrations` lock
+        // before invoking its factory - the factory may call back into
+        // `resolve` (e.g. `UserService` resolving its scoped `Logger`),
+        // which would deadlock on this same non-reentrant lock if it were
+        // still held here.
+        let registration = self.container.registrations.lock().unwrap().get(&type_id).cloned();
+        let registration = match registration {
+            Some(registration) => registration,
+            None => return Err(ContainerError::NotRegistered(type_name)),
+        };
+
+        if registration.lifetime == Lifetime::Scoped && self.scope.is_none() {
+            return Err(ContainerError::NoActiveScope(type_name));
+        }
+
+        let built = (registration.factory)(self);
+
+        match registration.lifetime {
+            Lifetime::Singleton => {
+                self.container.singletons.lock().unwrap().insert(type_id, built.clone());
+            }
+            Lifetime::Scoped => {
+                self.scope.as_ref().unwrap().cache.lock().unwrap().insert(type_id, built.clone());
+            }
+            Lifetime::Transient => {}
+        }
+
+        Ok(built.downcast::<T>().expect("TypeId match guarantees downcast"))
+    }
+}
+
+// ==============================================================================
+// Application Layer (Business Logic Using Scoped Dependencies)
+// ==============================================================================
+
+struct UserService {
+    logger: Arc<Logger>,
+}
+
+impl UserService {
+    fn new(logger: Arc<Logger>) -> Self {
+        Self { logger }
+    }
+
+    fn get_user(&self, user_id: i32) {
+        self.logger.info(&format!("Getting user {}", user_id));
+    }
+}
+
+// ==============================================================================
+// Composition Root (Application Entry Point)
+// ==============================================================================
+
+fn main() {
+    println!("======================================================================");
+    println!("Rust Scoped Container Lifetimes Dependency Injection Example");
+    println!("======================================================================");
+
+    println!("\n[Composition Root] Registering Database as a Singleton...");
+    let container = Arc::new(Container::new());
+    container.register(Lifetime::Singleton, |_| Database::new("postgresql://localhost/myapp".to_string()));
+
+    println!("[Composition Root] Registering RequestId as Transient (factory re-runs on every resolve)...");
+    let request_id_counter = AtomicU32::new(0);
+    container.register(Lifetime::Transient, move |_| {
+        RequestId(request_id_counter.fetch_add(1, Ordering::SeqCst) + 1)
+    });
+
+    println!("[Composition Root] Registering Logger as Scoped (one per request, own correlation id)...");
+    let request_counter = AtomicU32::new(0);
+    container.register(Lifetime::Scoped, move |_| {
+        let request_id = request_counter.fetch_add(1, Ordering::SeqCst) + 1;
+        Logger::new(format!("req-{}", request_id))
+    });
+
+    println!("[Composition Root] Registering UserService as Scoped (depends on the scoped Logger)...");
+    container.register(Lifetime::Scoped, |c| {
+        // Every resolve inside a given request's scope sees that request's
+        // Logger, so UserService gets the right correlation id without the
+        // caller threading it through manually.
+        UserService::new(c.resolve::<Logger>().expect("Logger is scoped too, resolved within this same scope"))
+    });
+
+    println!("\n======================================================================");
+    println!("Running Application");
+    println!("======================================================================\n");
+
+    println!("[Request 1] entering scope...");
+    let request_one = container.enter_scope();
+    let service_one = request_one.resolve::<UserService>().expect("UserService is registered");
+    service_one.get_user(1);
+
+    println!("\n[Request 2] entering a fresh scope...");
+    let request_two = container.enter_scope();
+    let service_two = request_two.resolve::<UserService>().expect("UserService is registered");
+    service_two.get_user(2);
+
+    // Both requests share the same Database singleton...
+    let db_one = request_one.resolve::<Database>().expect("Database is a registered singleton");
+    let db_two = request_two.resolve::<Database>().expect("Database is a registered singleton");
+    assert!(Arc::ptr_eq(&db_one, &db_two));
+    println!("\n✅ Both requests share the same Database singleton");
+
+    // ...but each got its own Logger (and therefore its own UserService).
+    let logger_one = request_one.resolve::<Logger>().expect("Logger is scoped");
+    let logger_two = request_two.resolve::<Logger>().expect("Logger is scoped");
+    assert!(!Arc::ptr_eq(&logger_one, &logger_two));
+    println!("✅ Each request got its own scoped Logger ({} vs {})", logger_one.correlation_id, logger_two.correlation_id);
+
+    // Transient never caches anywhere - the factory re-runs on every single
+    // resolve, even two calls back to back with no scope involved at all.
+    let request_id_one = container.resolve::<RequestId>().expect("RequestId is registered");
+    let request_id_two = container.resolve::<RequestId>().expect("RequestId is registered");
+    assert_ne!(request_id_one.0, request_id_two.0);
+    println!(
+        "✅ Transient RequestId re-ran its factory on each resolve ({} vs {})",
+        request_id_one.0, request_id_two.0
+    );
+
+    // Resolving a Scoped type directly on the root container - no scope -
+    // fails with a typed error instead of silently caching it forever.
+    match container.resolve::<Logger>() {
+        Ok(_) => unreachable!("Logger is Scoped and there's no active scope here"),
+        Err(err) => println!("✅ Expected resolve failure on root container: {}", err),
+    }
+}
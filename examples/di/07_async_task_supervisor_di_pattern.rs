@@ -0,0 +1,245 @@
+// Async Task Supervisor Dependency Injection Pattern - Rust Example
+//
+// Every previous example is synchronous request/response: a caller asks for
+// a user, gets an answer, done. Real services also need background work -
+// delivering queued notifications, draining a retry queue - that shouldn't
+// block the request path.
+//
+// This example adds a `Task` trait (`async fn run(self, ctx: Context)`) and a
+// supervisor `spawn(ctx, task)` that launches it on `tokio::spawn`. `Context`
+// carries the shared dependencies every task needs - here, an `Arc<Logger>`
+// and the shutdown `CancellationToken`; the `mpsc::Receiver` of notification
+// events is owned by the `NotificationWorker` task itself. `NotificationService` is
+// the producer side: instead of calling an `HTTPClient` directly, it pushes
+// `user.created` events onto a channel that the supervised
+// `NotificationWorker` task drains and "POSTs" asynchronously, decoupling
+// the request path from the HTTP call. A broadcast `CancellationToken` gives
+// every task a way to shut down gracefully instead of being killed mid-send.
+//
+// This example needs a `tokio` dependency (`rt-multi-thread`, `macros`,
+// `sync`, and `time` features) to build - this repo doesn't carry a
+// Cargo.toml yet, so `cargo run --example` isn't wired up here; treat this
+// file as a reference for the shape the supervisor would take once it is.
+
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use tokio::sync::{broadcast, mpsc};
+
+// ==============================================================================
+// Domain Models (Pure Business Logic - No Framework Dependencies)
+// ==============================================================================
+
+#[derive(Debug, Clone)]
+struct User {
+    id: i32,
+    name: String,
+}
+
+/// An event pushed onto the notification channel by the request path and
+/// drained by the background worker.
+#[derive(Debug, Clone)]
+struct UserCreatedEvent {
+    user: User,
+}
+
+// ==============================================================================
+// Infrastructure Layer (Implements Technical Concerns)
+// ==============================================================================
+
+struct Logger {
+    level: String,
+}
+
+impl Logger {
+    fn new(level: String) -> Self {
+        Self { level }
+    }
+
+    fn info(&self, message: &str) {
+        println!("[INFO] {}", message);
+    }
+}
+
+struct HTTPClient {
+    base_url: String,
+}
+
+impl HTTPClient {
+    fn new(base_url: String) -> Self {
+        Self { base_url }
+    }
+
+    async fn post(&self, path: &str, data: HashMap<String, String>) {
+        let url = format!("{}{}", self.base_url, path);
+        // Simulate network latency so the decoupling is actually observable.
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        println!("[HTTPClient] POST {} with {:?}", url, data);
+    }
+}
+
+/// A simple shutdown signal every supervised task selects on in its loop.
+/// Clones share the same broadcast sender and each `cancelled()` call
+/// subscribes its own receiver, so the supervisor can cancel every task at
+/// once. A `fired` flag records the cancel, so a task that was busy (not
+/// inside `cancelled()`) when it fired still sees it on its next loop.
+#[derive(Clone)]
+struct CancellationToken {
+    sender: Arc<broadcast::Sender<()>>,
+    fired: Arc<AtomicBool>,
+}
+
+impl CancellationToken {
+    fn new() -> Self {
+        let (sender, _) = broadcast::channel(1);
+        Self { sender: Arc::new(sender), fired: Arc::new(AtomicBool::new(false)) }
+    }
+
+    fn cancel(&self) {
+        self.fired.store(true, Ordering::SeqCst);
+        // A task may have already exited and dropped its receiver; that's
+        // not an error, just nothing left to wake up.
+        let _ = self.sender.send(());
+    }
+
+    async fn cancelled(&self) {
+        // Subscribe before checking the flag, so a cancel that lands in
+        // between is caught by one or the other.
+        let mut receiver = self.sender.subscribe();
+        if self.fired.load(Ordering::SeqCst) {
+            return;
+        }
+        let _ = receiver.recv().await;
+    }
+}
+
+// ==============================================================================
+// Task Supervisor
+// ==============================================================================
+
+/// Dependencies shared with every spawned task, resolved once up front.
+#[derive(Clone)]
+struct Context {
+    logger: Arc<Logger>,
+    cancellation: CancellationToken,
+}
+
+/// A unit of background work. `run` consumes `self`, so a task can own
+/// whatever it needs (e.g. the receiving end of a channel) without fighting
+/// the borrow checker across an `.await`.
+trait Task: Send + 'static {
+    fn run(self, ctx: Context) -> std::pin::Pin<Box<dyn std::future::Future<Output = ()> + Send>>;
+}
+
+/// Launches `task` on `tokio::spawn`, handing it `ctx`. Returns
+/// the `JoinHandle` so the caller can await graceful shutdown.
+fn spawn<T: Task>(ctx: Context, task: T) -> tokio::task::JoinHandle<()> {
+    tokio::spawn(task.run(ctx))
+}
+
+// ==============================================================================
+// Application Layer (Business Logic Using Channels Instead of Direct Calls)
+// ==============================================================================
+
+/// The request-path producer: pushes `user.created` events onto a channel
+/// instead of calling `HTTPClient` itself.
+struct NotificationService {
+    sender: mpsc::Sender<UserCreatedEvent>,
+    logger: Arc<Logger>,
+}
+
+impl NotificationService {
+    fn new(sender: mpsc::Sender<UserCreatedEvent>, logger: Arc<Logger>) -> Self {
+        Self { sender, logger }
+    }
+
+    async fn notify_user_created(&self, user: User) {
+        self.logger.info(&format!("Queuing notification for user {}", user.name));
+        let _ = self.sender.send(UserCreatedEvent { user }).await;
+    }
+}
+
+/// The background consumer: drains the channel and does the actual HTTP
+/// call, one event at a time, until the channel closes or cancellation
+/// fires.
+struct NotificationWorker {
+    receiver: mpsc::Receiver<UserCreatedEvent>,
+    http_client: Arc<HTTPClient>,
+}
+
+impl Task for NotificationWorker {
+    fn run(mut self, ctx: Context) -> std::pin::Pin<Box<dyn std::future::Future<Output = ()> + Send>> {
+        Box::pin(async move {
+            ctx.logger.info("NotificationWorker started");
+            loop {
+                tokio::select! {
+                    event = self.receiver.recv() => {
+                        match event {
+                            Some(event) => {
+                                let mut data = HashMap::new();
+                                data.insert("user_id".to_string(), event.user.id.to_string());
+                                data.insert("event".to_string(), "user.created".to_string());
+                                self.http_client.post("/notifications", data).await;
+                            }
+                            None => {
+                                ctx.logger.info("NotificationWorker channel closed, exiting");
+                                break;
+                            }
+                        }
+                    }
+                    _ = ctx.cancellation.cancelled() => {
+                        ctx.logger.info("NotificationWorker received shutdown signal, exiting");
+                        break;
+                    }
+                }
+            }
+        })
+    }
+}
+
+// ==============================================================================
+// Composition Root (Application Entry Point)
+// ==============================================================================
+
+#[tokio::main]
+async fn main() {
+    println!("======================================================================");
+    println!("Rust Async Task Supervisor Dependency Injection Example");
+    println!("======================================================================");
+
+    println!("\n[Composition Root] Creating infrastructure dependencies...");
+    let logger = Arc::new(Logger::new("INFO".to_string()));
+    let http_client = Arc::new(HTTPClient::new("https://api.example.com".to_string()));
+    let cancellation = CancellationToken::new();
+    let ctx = Context { logger: logger.clone(), cancellation: cancellation.clone() };
+
+    println!("[Composition Root] Wiring the notification channel and spawning the background worker...");
+    let (sender, receiver) = mpsc::channel(32);
+    let notifications = NotificationService::new(sender, logger.clone());
+    let worker = NotificationWorker { receiver, http_client };
+    let worker_handle = spawn(ctx, worker);
+
+    println!("\n======================================================================");
+    println!("Running Application");
+    println!("======================================================================\n");
+
+    // The request path returns immediately after queuing - it never waits
+    // on the HTTP call.
+    notifications
+        .notify_user_created(User { id: 1, name: "Alice".to_string() })
+        .await;
+    notifications
+        .notify_user_created(User { id: 2, name: "Bob".to_string() })
+        .await;
+    println!("✅ Request path queued 2 notifications without waiting on HTTPClient");
+
+    // Give the worker a moment to drain the queue, then shut it down
+    // gracefully via the cancellation token instead of aborting it.
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    println!("\n[Composition Root] Signaling graceful shutdown...");
+    cancellation.cancel();
+    worker_handle.await.expect("NotificationWorker task panicked");
+    println!("✅ NotificationWorker shut down gracefully");
+}